@@ -39,11 +39,15 @@ impl <R: Eq + Copy + Display> Banker<R> {
         self.num_tasks
     }
 
-    /// Remove a task from the allocated list
+    /// Remove a task from the allocated list, restoring whatever it still
+    /// held back to `available` so the resources don't leak.
     pub fn remove_task(&mut self, task_id: usize) -> bool {
         if task_id >= self.num_tasks {
             return false;
         }
+        for resource_id in 0..self.resources.len() {
+            self.available[resource_id] += self.allocated[task_id][resource_id];
+        }
         self.max[task_id].fill(0);
         self.allocated[task_id].fill(0);
         self.need[task_id].fill(0);
@@ -84,7 +88,8 @@ impl <R: Eq + Copy + Display> Banker<R> {
         self.resources.iter().position(|res| res == &Some(resource))
     }
 
-    /// Allocate a resource to a task
+    /// Give `amount` units of `resource` back from `task_id`, restoring
+    /// them to `available`.
     pub fn release(&mut self, task_id: usize, resource: R, amount: usize) -> bool {
         if task_id >= self.num_tasks {
             return false;
@@ -99,9 +104,19 @@ impl <R: Eq + Copy + Display> Banker<R> {
         }
 
         self.allocated[task_id][resource_id] -= amount;
+        self.available[resource_id] += amount;
         true
     }
 
+    /// Whether at least `amount` units of `resource` are currently free,
+    /// independent of whether granting them would be "safe".
+    pub fn has_available(&self, resource: R, amount: usize) -> bool {
+        let Some(resource_id) = self.resource_id(resource) else {
+            return false;
+        };
+        amount <= self.available[resource_id]
+    }
+
     /// Allocate a resource to a task
     pub fn is_safe(&self) -> bool {
         let mut work = self.available.clone();
@@ -0,0 +1,7 @@
+//! Synchronization primitives shared by the kernel's singletons
+
+mod deadlock_detection;
+mod up;
+
+pub use deadlock_detection::Banker;
+pub use up::UPSafeCell;
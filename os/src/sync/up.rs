@@ -0,0 +1,30 @@
+//! Uniprocessor interior mutability primitive
+
+use core::cell::{RefCell, RefMut};
+
+/// Wrapper around a `RefCell` that allows sharing a value across kernel
+/// singletons on a uniprocessor, trusting the caller never to hold two
+/// `exclusive_access` borrows at once.
+pub struct UPSafeCell<T> {
+    inner: RefCell<T>,
+}
+
+unsafe impl<T> Sync for UPSafeCell<T> {}
+
+impl<T> UPSafeCell<T> {
+    /// Wrap `value` for uniprocessor-exclusive access.
+    ///
+    /// # Safety
+    /// The caller must guarantee that accesses via [`Self::exclusive_access`]
+    /// never overlap (no interrupts, no multi-core reentrancy).
+    pub unsafe fn new(value: T) -> Self {
+        Self {
+            inner: RefCell::new(value),
+        }
+    }
+
+    /// Borrow the inner value exclusively, panicking if already borrowed.
+    pub fn exclusive_access(&self) -> RefMut<'_, T> {
+        self.inner.borrow_mut()
+    }
+}
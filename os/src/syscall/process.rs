@@ -1,10 +1,16 @@
 //! Process management syscalls
+use alloc::vec::Vec;
+
 use crate::{
     config::MAX_SYSCALL_NUM, mm::{translated_byte_buffer, MapPermission}, task::{
-        change_program_brk, current_user_token, exit_current_and_run_next, suspend_current_and_run_next, TaskStatus, TASK_MANAGER
+        change_program_brk, current_user_token, exit_current_and_run_next, suspend_current_and_run_next,
+        SchedulerClass, SchedulerPolicy, SeccompAction, TaskStatus, TASK_MANAGER
     }, timer::get_time_us
 };
 
+/// The syscall id `sys_mmap` is dispatched under, for seccomp gating.
+const SYS_MMAP: usize = 222;
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct TimeVal {
@@ -57,6 +63,19 @@ fn copy_to_virt<T>(src: &T, dst: *mut T) {
     }
 }
 
+fn copy_from_virt<T: Copy>(src: *const T) -> T {
+    let src_buf_ptr: *const u8 = unsafe { core::mem::transmute(src) };
+    let len = core::mem::size_of::<T>();
+
+    let src_frames = translated_byte_buffer(current_user_token(), src_buf_ptr, len);
+
+    let mut buf: Vec<u8> = Vec::with_capacity(len);
+    for src_frame in src_frames {
+        buf.extend_from_slice(src_frame);
+    }
+    unsafe { core::ptr::read(buf.as_ptr() as *const T) }
+}
+
 /// YOUR JOB: get time with second and microsecond
 /// HINT: You might reimplement it with virtual memory management.
 /// HINT: What if [`TimeVal`] is splitted by two pages ?
@@ -73,12 +92,43 @@ pub fn sys_get_time(_ts: *mut TimeVal, _tz: usize) -> isize {
     0
 }
 
-/// YOUR JOB: Finish sys_task_info to pass testcases
+/// Finish sys_task_info to pass testcases
 /// HINT: You might reimplement it with virtual memory management.
 /// HINT: What if [`TaskInfo`] is splitted by two pages ?
 pub fn sys_task_info(_ti: *mut TaskInfo) -> isize {
-    trace!("kernel: sys_task_info NOT IMPLEMENTED YET!");
-    -1
+    trace!("kernel: sys_task_info");
+    let syscall_times = TASK_MANAGER.current_task_info().syscall_times;
+    let task_info = TaskInfo {
+        status: TASK_MANAGER.current_task_status(),
+        syscall_times,
+        time: TASK_MANAGER.current_elapsed_ms(),
+    };
+    copy_to_virt(&task_info, _ti);
+    0
+}
+
+/// The current task's on-CPU time split from its wall-clock elapsed time.
+/// A separate struct and syscall from [`TaskInfo`]/`sys_task_info` so that
+/// ABI never has to widen.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CpuTimeInfo {
+    /// Milliseconds actually spent `Running` (excludes time spent
+    /// `Ready`/`Stopped`/waiting on a lock)
+    pub cpu_time_ms: usize,
+    /// Milliseconds of wall-clock time since the task was first scheduled
+    pub elapsed_ms: usize,
+}
+
+/// Query the current task's on-CPU time versus its wall-clock elapsed time.
+pub fn sys_task_cpu_time(ti: *mut CpuTimeInfo) -> isize {
+    trace!("kernel: sys_task_cpu_time");
+    let info = CpuTimeInfo {
+        cpu_time_ms: TASK_MANAGER.current_cpu_time_ms(),
+        elapsed_ms: TASK_MANAGER.current_elapsed_ms(),
+    };
+    copy_to_virt(&info, ti);
+    0
 }
 
 bitflags! {
@@ -109,6 +159,10 @@ impl From<MmapProt> for MapPermission {
 // YOUR JOB: Implement mmap.
 pub fn sys_mmap(start: usize, len: usize, prot: usize) -> isize {
     debug!("kernel: sys_mmap start: {:#x}, len: {:#x}, prot: {:#x}", start, len, prot);
+    if let Err(errno) = TASK_MANAGER.sys_call_inc(SYS_MMAP) {
+        return errno;
+    }
+
     let Some(prot) = MmapProt::from_bits(prot) else {
         return -1;
     };
@@ -137,6 +191,115 @@ pub fn sys_munmap(start: usize, len: usize) -> isize {
     0
 }
 
+/// Install (extend) the calling task's seccomp filter.
+///
+/// `_mode` is reserved (mirroring `SECCOMP_SET_MODE_FILTER` vs `_STRICT`
+/// and currently unused); `rules` points at `len` user-space
+/// `(syscall_id, action)` pairs, `action` one of `0`=Allow, `1`=Log,
+/// `2`=ErrnoDeny, `3`=Trap. Every decoded `syscall_id` is checked against
+/// `MAX_SYSCALL_NUM` before it's used to index a filter table — an
+/// out-of-range id fails the whole call instead of indexing out of bounds.
+pub fn sys_seccomp_install(_mode: usize, rules: *const (usize, usize), len: usize) -> isize {
+    trace!("kernel: sys_seccomp_install");
+    if len > MAX_SYSCALL_NUM {
+        return -1;
+    }
+
+    let mut parsed: Vec<(usize, SeccompAction)> = Vec::with_capacity(len);
+    for i in 0..len {
+        let (syscall_id, action) = copy_from_virt(unsafe { rules.add(i) });
+        if syscall_id >= MAX_SYSCALL_NUM {
+            return -1;
+        }
+        let action = match action {
+            0 => SeccompAction::Allow,
+            1 => SeccompAction::Log,
+            2 => SeccompAction::ErrnoDeny,
+            3 => SeccompAction::Trap,
+            _ => return -1,
+        };
+        parsed.push((syscall_id, action));
+    }
+
+    TASK_MANAGER.install_seccomp_filter(&parsed);
+    0
+}
+
+/// Set the current task's nice value (`SCHED_NORMAL`) or static priority
+/// (`SCHED_FIFO`/`SCHED_RR`) without changing its scheduling class.
+///
+/// `prio` arrives as a full-width `isize` and must be validated before it is
+/// narrowed to `i32`: truncating first would let an out-of-range value wrap
+/// into something that passes [`SchedulerPolicy::new`]'s range check.
+pub fn sys_set_priority(prio: isize) -> isize {
+    trace!("kernel: sys_set_priority");
+    let Ok(prio) = i32::try_from(prio) else {
+        return -1;
+    };
+
+    let class = TASK_MANAGER.current_sched_policy().class;
+    match SchedulerPolicy::new(class, prio) {
+        Ok(policy) => {
+            TASK_MANAGER.set_sched_policy(policy);
+            0
+        }
+        Err(_) => -1,
+    }
+}
+
+/// Switch the current task's scheduling class and priority.
+///
+/// `policy` selects the class (`0` = `SCHED_NORMAL`, `1` = `SCHED_FIFO`,
+/// `2` = `SCHED_RR`); `priority` is validated at full width for the same
+/// reason as in [`sys_set_priority`].
+pub fn sys_sched_setscheduler(policy: isize, priority: isize) -> isize {
+    trace!("kernel: sys_sched_setscheduler");
+    let Ok(priority) = i32::try_from(priority) else {
+        return -1;
+    };
+
+    let class = match policy {
+        0 => SchedulerClass::Normal,
+        1 => SchedulerClass::Fifo,
+        2 => SchedulerClass::RoundRobin,
+        _ => return -1,
+    };
+
+    match SchedulerPolicy::new(class, priority) {
+        Ok(policy) => {
+            TASK_MANAGER.set_sched_policy(policy);
+            0
+        }
+        Err(_) => -1,
+    }
+}
+
+/// A subset of Linux's `ptrace(2)` requests this kernel understands.
+mod ptrace_request {
+    pub const TRACEME: usize = 0;
+    pub const PEEKDATA: usize = 2;
+    pub const PEEKUSER: usize = 3;
+    pub const CONT: usize = 7;
+    pub const SINGLESTEP: usize = 9;
+    pub const ATTACH: usize = 16;
+}
+
+/// `TRACEME`/`ATTACH` establish the tracer link, `CONT`/`SINGLESTEP` resume
+/// a `Stopped` tracee, and `PEEKDATA`/`PEEKUSER` read one of its saved
+/// registers (`addr` is the register index, see `TaskContext::word_at`).
+/// `_data` is unused by every request this kernel implements.
+pub fn sys_ptrace(request: usize, pid: usize, addr: usize, _data: usize) -> isize {
+    trace!("kernel: sys_ptrace");
+    match request {
+        ptrace_request::TRACEME => TASK_MANAGER.ptrace_traceme(),
+        ptrace_request::ATTACH => TASK_MANAGER.ptrace_attach(pid),
+        ptrace_request::CONT => TASK_MANAGER.ptrace_cont(pid),
+        ptrace_request::SINGLESTEP => TASK_MANAGER.ptrace_single_step(pid),
+        ptrace_request::PEEKDATA | ptrace_request::PEEKUSER => TASK_MANAGER.ptrace_peek(pid, addr),
+        _ => -1,
+    }
+}
+
 /// change data segment size
 pub fn sys_sbrk(size: i32) -> isize {
     trace!("kernel: sys_sbrk");
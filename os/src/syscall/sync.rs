@@ -0,0 +1,55 @@
+//! Synchronization syscalls
+
+use crate::task::TASK_MANAGER;
+
+/// Turn the kernel's Banker's-algorithm deadlock detector on (`enabled != 0`)
+/// or off for every mutex/semaphore acquisition made from here on.
+pub fn sys_enable_deadlock_detect(enabled: usize) -> isize {
+    trace!("kernel: sys_enable_deadlock_detect");
+    TASK_MANAGER.enable_deadlock_detect(enabled != 0);
+    0
+}
+
+/// Create a mutex, returning its id.
+pub fn sys_mutex_create() -> isize {
+    trace!("kernel: sys_mutex_create");
+    TASK_MANAGER.mutex_create() as isize
+}
+
+/// Lock `mutex_id`, blocking until it's free.
+///
+/// Once it is free, returns `-0xDEAD` instead of taking it if deadlock
+/// detection is enabled and granting the lock can't be proven safe. The wait
+/// while another task holds it is not checked by the detector.
+pub fn sys_mutex_lock(mutex_id: usize) -> isize {
+    trace!("kernel: sys_mutex_lock");
+    TASK_MANAGER.mutex_lock(mutex_id)
+}
+
+/// Unlock `mutex_id`.
+pub fn sys_mutex_unlock(mutex_id: usize) -> isize {
+    trace!("kernel: sys_mutex_unlock");
+    TASK_MANAGER.mutex_unlock(mutex_id)
+}
+
+/// Create a semaphore with `res_count` permits, returning its id.
+pub fn sys_semaphore_create(res_count: usize) -> isize {
+    trace!("kernel: sys_semaphore_create");
+    TASK_MANAGER.semaphore_create(res_count) as isize
+}
+
+/// Acquire one permit of `sem_id`, blocking until one is free.
+///
+/// Once one is free, returns `-0xDEAD` instead of taking it if deadlock
+/// detection is enabled and granting the permit can't be proven safe. The
+/// wait while no permit is free is not checked by the detector.
+pub fn sys_semaphore_down(sem_id: usize) -> isize {
+    trace!("kernel: sys_semaphore_down");
+    TASK_MANAGER.semaphore_down(sem_id)
+}
+
+/// Release one permit of `sem_id` held by the current task.
+pub fn sys_semaphore_up(sem_id: usize) -> isize {
+    trace!("kernel: sys_semaphore_up");
+    TASK_MANAGER.semaphore_up(sem_id)
+}
@@ -0,0 +1,664 @@
+//! Task management implementation
+//!
+//! Everything process-related is exposed through the [`TaskManager`]
+//! singleton in [`TASK_MANAGER`]; callers outside this module should not
+//! reach into [`TaskManagerInner`] directly.
+
+mod task;
+
+#[cfg(test)]
+mod pct;
+
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
+use core::cell::RefMut;
+use lazy_static::lazy_static;
+
+#[cfg(test)]
+use pct::PctScheduler;
+
+use crate::{
+    config::MAX_APP_NUM,
+    loader::{get_num_app, init_app_cx},
+    sync::{Banker, UPSafeCell},
+};
+
+pub use task::{
+    max_priority_for_sched_policy, min_priority_for_sched_policy, ExitReason, SchedulerClass,
+    SchedulerPolicy, SeccompAction, TaskControlBlock, TaskInfo, TaskStatus, MAX_NICE,
+    MAX_RT_PRIORITY, MIN_NICE, MIN_RT_PRIORITY,
+};
+
+/// The task context saved and restored on every context switch.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TaskContext {
+    ra: usize,
+    sp: usize,
+    s: [usize; 12],
+}
+
+impl TaskContext {
+    /// An all-zero context, for a task that has never run.
+    pub fn zero_init() -> Self {
+        Self {
+            ra: 0,
+            sp: 0,
+            s: [0; 12],
+        }
+    }
+
+    /// A context that, when switched to, returns into `__restore` with `sp`
+    /// pointing at the trap frame built by [`init_app_cx`].
+    pub fn goto_restore(kstack_ptr: usize) -> Self {
+        extern "C" {
+            fn __restore();
+        }
+        Self {
+            ra: __restore as usize,
+            sp: kstack_ptr,
+            s: [0; 12],
+        }
+    }
+
+    /// Read register `index` out of this saved context: `0` = `ra`,
+    /// `1` = `sp`, `2..=13` = `s0..=s11`. Used by `PTRACE_PEEKUSER`.
+    pub fn word_at(&self, index: usize) -> Option<usize> {
+        match index {
+            0 => Some(self.ra),
+            1 => Some(self.sp),
+            2..=13 => Some(self.s[index - 2]),
+            _ => None,
+        }
+    }
+}
+
+struct TaskManagerInner {
+    tasks: Vec<TaskControlBlock>,
+    current_task: usize,
+    /// Whether lock/semaphore acquisition should refuse requests the
+    /// Banker's algorithm can't prove safe.
+    deadlock_detect_enabled: bool,
+    /// Mutex/semaphore ids share this resource space in `banker`.
+    banker: Banker<usize>,
+    next_resource_id: usize,
+    /// mutex id -> the task currently holding it, `None` if free.
+    mutex_owner: BTreeMap<usize, Option<usize>>,
+    /// semaphore id -> task ids currently holding a permit (one entry per
+    /// permit held, so a task can appear more than once).
+    semaphore_permits: BTreeMap<usize, Vec<usize>>,
+    /// tracer task id -> ids of its tracees that have exited since the
+    /// tracer last drained this with `take_exited_tracees`.
+    tracer_notifications: BTreeMap<usize, Vec<usize>>,
+    /// `Some` once a test has called `enable_pct`, switching scheduling
+    /// decisions over to deterministic PCT.
+    #[cfg(test)]
+    pct: Option<PctScheduler>,
+}
+
+impl TaskManagerInner {
+    /// Index of the next `Ready` task to run. Under `#[cfg(test)]`, once
+    /// `enable_pct` has run, this defers to the deterministic PCT
+    /// scheduler; otherwise it picks by [`SchedulerPolicy`]: any
+    /// `Fifo`/`RoundRobin` task outranks every `Normal` task, ties broken in
+    /// rotation order starting just after the current task.
+    fn select_next_ready(&mut self) -> Option<usize> {
+        #[cfg(test)]
+        if let Some(pct) = self.pct.as_mut() {
+            pct.on_step(self.current_task);
+            let num_tasks = self.tasks.len();
+            let current = self.current_task;
+            let ready = (current + 1..current + 1 + num_tasks)
+                .map(|id| id % num_tasks)
+                .filter(|id| self.tasks[*id].is_ready());
+            return pct.select_next(ready);
+        }
+
+        self.select_next_ready_by_policy()
+    }
+
+    fn select_next_ready_by_policy(&self) -> Option<usize> {
+        let num_tasks = self.tasks.len();
+        (self.current_task + 1..self.current_task + 1 + num_tasks)
+            .map(|id| id % num_tasks)
+            .filter(|id| self.tasks[*id].is_ready())
+            // `min_by_key` keeps the first of equal keys, so ties go to the
+            // earliest task in rotation order.
+            .min_by_key(|id| core::cmp::Reverse(self.tasks[*id].sched_policy().scheduling_key()))
+    }
+
+    /// Give back every mutex and semaphore permit `task_id` is still
+    /// holding and drop its Banker rows, so an exiting task can never leave
+    /// a lock permanently unavailable.
+    fn release_resources_held_by(&mut self, task_id: usize) {
+        for (&mutex_id, owner) in self.mutex_owner.iter_mut() {
+            if *owner == Some(task_id) {
+                *owner = None;
+                self.banker.release(task_id, mutex_id, 1);
+            }
+        }
+        for (&sem_id, holders) in self.semaphore_permits.iter_mut() {
+            let held = holders.iter().filter(|&&id| id == task_id).count();
+            holders.retain(|&id| id != task_id);
+            if held > 0 {
+                self.banker.release(task_id, sem_id, held);
+            }
+        }
+        self.banker.remove_task(task_id);
+    }
+
+    /// Queue an exit notification for `task_id`'s tracer, if it has one.
+    fn notify_tracer_of_exit(&mut self, task_id: usize) {
+        if let Some(tracer) = self.tasks[task_id].tracer() {
+            self.tracer_notifications
+                .entry(tracer)
+                .or_default()
+                .push(task_id);
+        }
+    }
+}
+
+/// The task manager, owning every [`TaskControlBlock`] in the system.
+pub struct TaskManager {
+    num_app: usize,
+    inner: UPSafeCell<TaskManagerInner>,
+}
+
+lazy_static! {
+    /// The global task manager
+    pub static ref TASK_MANAGER: TaskManager = {
+        let num_app = get_num_app();
+        let mut tasks = [TaskControlBlock {
+            status: TaskStatus::UnInit,
+            info: TaskInfo::new(TaskContext::zero_init(), 0),
+        }; MAX_APP_NUM];
+        for (i, task) in tasks.iter_mut().enumerate().take(num_app) {
+            task.info.task_cx = TaskContext::goto_restore(init_app_cx(i));
+            task.status = TaskStatus::Ready;
+        }
+        let mut banker = Banker::new();
+        for _ in 1..num_app {
+            banker.add_task();
+        }
+        TaskManager {
+            num_app,
+            inner: unsafe {
+                UPSafeCell::new(TaskManagerInner {
+                    tasks: tasks[..num_app].to_vec(),
+                    current_task: 0,
+                    deadlock_detect_enabled: false,
+                    banker,
+                    next_resource_id: 0,
+                    mutex_owner: BTreeMap::new(),
+                    semaphore_permits: BTreeMap::new(),
+                    tracer_notifications: BTreeMap::new(),
+                    #[cfg(test)]
+                    pct: None,
+                })
+            },
+        }
+    };
+}
+
+impl TaskManager {
+    /// Run the first task, never returning.
+    fn run_first_task(&self) -> ! {
+        let mut inner = self.inner.exclusive_access();
+        let task0 = &mut inner.tasks[0];
+        task0
+            .try_turn_to_running()
+            .expect("TaskManager::run_first_task: task 0 is not Ready");
+        let next_task_cx_ptr = task0.cx() as *const TaskContext;
+        drop(inner);
+        let mut _unused = TaskContext::zero_init();
+        extern "C" {
+            fn __switch(current_task_cx_ptr: *mut TaskContext, next_task_cx_ptr: *const TaskContext);
+        }
+        unsafe {
+            __switch(&mut _unused as *mut TaskContext, next_task_cx_ptr);
+        }
+        panic!("unreachable in TaskManager::run_first_task!");
+    }
+
+    /// Pick the next `Ready` task by [`SchedulerPolicy`] and switch to it.
+    /// The caller must already have moved the current task out of `Running`.
+    fn switch_away(&self, mut inner: RefMut<'_, TaskManagerInner>) {
+        let current = inner.current_task;
+        let Some(next) = inner.select_next_ready() else {
+            drop(inner);
+            panic!("All applications completed!");
+        };
+
+        inner.tasks[next]
+            .try_turn_to_running()
+            .expect("TaskManager::switch_away: chosen task is not Ready");
+        inner.current_task = next;
+        let current_task_cx_ptr = &mut inner.tasks[current].info.task_cx as *mut TaskContext;
+        let next_task_cx_ptr = inner.tasks[next].cx() as *const TaskContext;
+        drop(inner);
+        extern "C" {
+            fn __switch(current_task_cx_ptr: *mut TaskContext, next_task_cx_ptr: *const TaskContext);
+        }
+        unsafe {
+            __switch(current_task_cx_ptr, next_task_cx_ptr);
+        }
+    }
+
+    /// Move the current task to `new_status`, then switch to the next
+    /// `Ready` task (or stop the kernel if none remains).
+    fn reschedule_current(&self, new_status: TaskStatus) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        match new_status {
+            TaskStatus::Ready => inner.tasks[current]
+                .try_turn_to_ready()
+                .expect("TaskManager::reschedule_current: current task is not Running"),
+            TaskStatus::Exited => {
+                inner.tasks[current].turn_to_exited();
+                inner.release_resources_held_by(current);
+                inner.notify_tracer_of_exit(current);
+            }
+            _ => panic!("TaskManager::reschedule_current: unsupported target status"),
+        }
+        self.switch_away(inner);
+    }
+
+    /// Kill the current task because its seccomp filter trapped on the
+    /// syscall it just issued, then switch to the next `Ready` task.
+    fn kill_current_for_seccomp_trap(&self) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].turn_to_exited_seccomp_trap();
+        inner.release_resources_held_by(current);
+        inner.notify_tracer_of_exit(current);
+        self.switch_away(inner);
+    }
+
+    /// Stop the current task because it's traced and just made a syscall,
+    /// then switch to the next `Ready` task. The tracer resumes it with
+    /// `PTRACE_CONT`/`PTRACE_SINGLESTEP`.
+    fn stop_current_for_trace(&self) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current]
+            .try_turn_to_stopped()
+            .expect("TaskManager::stop_current_for_trace: current task is not Running");
+        self.switch_away(inner);
+    }
+
+    /// Replace the scheduling policy of the currently running task.
+    pub fn set_sched_policy(&self, policy: SchedulerPolicy) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].set_sched_policy(policy);
+    }
+
+    /// Consult the current task's seccomp filter for `syscall_id` and, if
+    /// it's allowed to proceed, record it as called.
+    ///
+    /// Returns `Err(errno)` when the syscall must not run at all: the
+    /// caller (the syscall handler gating itself on this) must return that
+    /// value instead of doing its own work. A `Trap` verdict kills the task
+    /// as a side effect of returning `Err`; if the task is traced and the
+    /// verdict allows the syscall through, it stops for its tracer instead
+    /// of running it immediately.
+    pub fn sys_call_inc(&self, syscall_id: usize) -> Result<(), isize> {
+        let (action, traced) = {
+            let mut inner = self.inner.exclusive_access();
+            let current = inner.current_task;
+            let action = inner.tasks[current].seccomp_action_for(syscall_id);
+            if matches!(action, SeccompAction::Allow | SeccompAction::Log) {
+                inner.tasks[current].sys_call_inc(syscall_id);
+            }
+            (action, inner.tasks[current].tracer())
+        };
+
+        match action {
+            SeccompAction::Allow | SeccompAction::Log => {
+                if traced.is_some() {
+                    self.stop_current_for_trace();
+                }
+                Ok(())
+            }
+            SeccompAction::ErrnoDeny => Err(-1),
+            SeccompAction::Trap => {
+                self.kill_current_for_seccomp_trap();
+                Err(-1)
+            }
+        }
+    }
+
+    /// Install (extend) the current task's seccomp filter.
+    ///
+    /// `rules` must already have every `syscall_id < MAX_SYSCALL_NUM`: the
+    /// `sys_seccomp_install` syscall is responsible for that check.
+    pub fn install_seccomp_filter(&self, rules: &[(usize, SeccompAction)]) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].install_seccomp_filter(rules);
+    }
+
+    /// The [`TaskInfo`] of the currently running task.
+    pub fn current_task_info(&self) -> TaskInfo {
+        let inner = self.inner.exclusive_access();
+        *inner.tasks[inner.current_task].info()
+    }
+
+    /// The [`SchedulerPolicy`] of the currently running task.
+    pub fn current_sched_policy(&self) -> SchedulerPolicy {
+        let inner = self.inner.exclusive_access();
+        inner.tasks[inner.current_task].sched_policy()
+    }
+
+    /// The [`TaskStatus`] of the currently running task.
+    pub fn current_task_status(&self) -> TaskStatus {
+        let inner = self.inner.exclusive_access();
+        inner.tasks[inner.current_task].status()
+    }
+
+    /// Milliseconds the currently running task has spent on-CPU.
+    pub fn current_cpu_time_ms(&self) -> usize {
+        let inner = self.inner.exclusive_access();
+        inner.tasks[inner.current_task].cpu_time_ms()
+    }
+
+    /// Milliseconds of wall-clock time since the currently running task was
+    /// first scheduled.
+    pub fn current_elapsed_ms(&self) -> usize {
+        let inner = self.inner.exclusive_access();
+        inner.tasks[inner.current_task].elapsed_ms()
+    }
+
+    /// How many apps this kernel was built with.
+    pub fn num_app(&self) -> usize {
+        self.num_app
+    }
+
+    /// Turn the Banker's-algorithm safety check on lock/semaphore
+    /// acquisition on or off.
+    pub fn enable_deadlock_detect(&self, enabled: bool) {
+        let mut inner = self.inner.exclusive_access();
+        inner.deadlock_detect_enabled = enabled;
+    }
+
+    /// Register a new mutex as a single-unit Banker resource, returning its id.
+    pub fn mutex_create(&self) -> usize {
+        let mut inner = self.inner.exclusive_access();
+        let id = inner.next_resource_id;
+        inner.next_resource_id += 1;
+        inner.banker.add_resource(id, 1);
+        inner.mutex_owner.insert(id, None);
+        id
+    }
+
+    /// Register a new semaphore as a Banker resource with `res_count` units,
+    /// returning its id.
+    pub fn semaphore_create(&self, res_count: usize) -> usize {
+        let mut inner = self.inner.exclusive_access();
+        let id = inner.next_resource_id;
+        inner.next_resource_id += 1;
+        inner.banker.add_resource(id, res_count);
+        inner.semaphore_permits.insert(id, Vec::new());
+        id
+    }
+
+    /// Lock `mutex_id`, blocking until it's free. Once it is free, returns
+    /// `-0xDEAD` instead of taking it if deadlock detection is enabled and
+    /// granting the lock can't be proven safe. The wait while another task
+    /// holds it is not checked by the detector.
+    pub fn mutex_lock(&self, mutex_id: usize) -> isize {
+        loop {
+            let mut inner = self.inner.exclusive_access();
+            let current = inner.current_task;
+            if inner.mutex_owner.get(&mutex_id).copied().flatten().is_some() {
+                drop(inner);
+                suspend_current_and_run_next();
+                continue;
+            }
+
+            if inner.deadlock_detect_enabled && !inner.banker.try_request(current, mutex_id, 1) {
+                return -0xDEAD;
+            }
+
+            inner.banker.request(current, mutex_id, 1);
+            inner.mutex_owner.insert(mutex_id, Some(current));
+            return 0;
+        }
+    }
+
+    /// Unlock `mutex_id`. Fails if the current task doesn't hold it.
+    pub fn mutex_unlock(&self, mutex_id: usize) -> isize {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        if inner.mutex_owner.get(&mutex_id).copied().flatten() != Some(current) {
+            return -1;
+        }
+        inner.mutex_owner.insert(mutex_id, None);
+        inner.banker.release(current, mutex_id, 1);
+        0
+    }
+
+    /// Acquire one permit of `sem_id`, blocking until one is free. Once one
+    /// is free, returns `-0xDEAD` instead of taking it if deadlock detection
+    /// is enabled and granting the permit can't be proven safe. The wait
+    /// while no permit is free is not checked by the detector.
+    pub fn semaphore_down(&self, sem_id: usize) -> isize {
+        loop {
+            let mut inner = self.inner.exclusive_access();
+            let current = inner.current_task;
+            if !inner.banker.has_available(sem_id, 1) {
+                drop(inner);
+                suspend_current_and_run_next();
+                continue;
+            }
+
+            if inner.deadlock_detect_enabled && !inner.banker.try_request(current, sem_id, 1) {
+                return -0xDEAD;
+            }
+
+            inner.banker.request(current, sem_id, 1);
+            inner.semaphore_permits.entry(sem_id).or_default().push(current);
+            return 0;
+        }
+    }
+
+    /// Release one permit of `sem_id` held by the current task.
+    pub fn semaphore_up(&self, sem_id: usize) -> isize {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        let Some(holders) = inner.semaphore_permits.get_mut(&sem_id) else {
+            return -1;
+        };
+        let Some(pos) = holders.iter().position(|&id| id == current) else {
+            return -1;
+        };
+        holders.remove(pos);
+        inner.banker.release(current, sem_id, 1);
+        0
+    }
+
+    /// `PTRACE_ATTACH`'s implicit target when a task calls `PTRACE_TRACEME`:
+    /// this kernel has no `fork`/parent-child graph to attach along, so by
+    /// convention a self-tracing task is traced by task 0.
+    const INIT_TRACER: usize = 0;
+
+    /// `PTRACE_TRACEME`: trace the current task from [`Self::INIT_TRACER`].
+    pub fn ptrace_traceme(&self) -> isize {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].attach_tracer(Self::INIT_TRACER);
+        0
+    }
+
+    /// `PTRACE_ATTACH`: make the current task the tracer of `pid`.
+    pub fn ptrace_attach(&self, pid: usize) -> isize {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        let Some(task) = inner.tasks.get_mut(pid) else {
+            return -1;
+        };
+        task.attach_tracer(current);
+        0
+    }
+
+    /// `PTRACE_CONT`: resume a `Stopped` tracee.
+    pub fn ptrace_cont(&self, pid: usize) -> isize {
+        let mut inner = self.inner.exclusive_access();
+        let Some(task) = inner.tasks.get_mut(pid) else {
+            return -1;
+        };
+        match task.try_turn_to_ready_from_stopped() {
+            Ok(()) => 0,
+            Err(_) => -1,
+        }
+    }
+
+    /// `PTRACE_SINGLESTEP`: this kernel only stops at syscall granularity,
+    /// so resuming one stop at a time already gives single-step semantics;
+    /// it's identical to `PTRACE_CONT` here.
+    pub fn ptrace_single_step(&self, pid: usize) -> isize {
+        self.ptrace_cont(pid)
+    }
+
+    /// `PTRACE_PEEKDATA`/`PTRACE_PEEKUSER`: read register `addr` (see
+    /// [`TaskContext::word_at`]) out of `pid`'s saved context.
+    pub fn ptrace_peek(&self, pid: usize, addr: usize) -> isize {
+        let inner = self.inner.exclusive_access();
+        let Some(task) = inner.tasks.get(pid) else {
+            return -1;
+        };
+        match task.cx().word_at(addr) {
+            Some(word) => word as isize,
+            None => -1,
+        }
+    }
+
+    /// Every tracee of `tracer` that has exited since the last call,
+    /// draining the notification queue.
+    pub fn take_exited_tracees(&self, tracer: usize) -> Vec<usize> {
+        let mut inner = self.inner.exclusive_access();
+        inner.tracer_notifications.remove(&tracer).unwrap_or_default()
+    }
+}
+
+/// Run the first task.
+pub fn run_first_task() {
+    TASK_MANAGER.run_first_task();
+}
+
+/// Suspend the current task and run the next one.
+pub fn suspend_current_and_run_next() {
+    TASK_MANAGER.reschedule_current(TaskStatus::Ready);
+}
+
+/// Exit the current task and run the next one.
+pub fn exit_current_and_run_next() {
+    TASK_MANAGER.reschedule_current(TaskStatus::Exited);
+}
+
+#[cfg(test)]
+impl TaskManager {
+    /// Build an empty task manager for unit tests, bypassing the app loader.
+    fn new_for_test() -> Self {
+        Self {
+            num_app: 0,
+            inner: unsafe {
+                UPSafeCell::new(TaskManagerInner {
+                    tasks: Vec::new(),
+                    current_task: 0,
+                    deadlock_detect_enabled: false,
+                    banker: Banker::new(),
+                    next_resource_id: 0,
+                    mutex_owner: BTreeMap::new(),
+                    semaphore_permits: BTreeMap::new(),
+                    tracer_notifications: BTreeMap::new(),
+                    #[cfg(test)]
+                    pct: None,
+                })
+            },
+        }
+    }
+
+    /// Push a `Ready` task with a default [`TaskInfo`] for unit tests,
+    /// returning its index.
+    fn push_task_for_test(&self) -> usize {
+        let mut inner = self.inner.exclusive_access();
+        inner.tasks.push(TaskControlBlock {
+            status: TaskStatus::Ready,
+            info: TaskInfo::new(TaskContext::zero_init(), 0),
+        });
+        inner.tasks.len() - 1
+    }
+
+    /// Switch scheduling decisions over to a deterministic PCT schedule,
+    /// seeded reproducibly over the tasks pushed so far.
+    fn enable_pct(&self, seed: u64, depth: i32, max_steps: usize) {
+        let mut inner = self.inner.exclusive_access();
+        let num_tasks = inner.tasks.len();
+        inner.pct = Some(PctScheduler::new(seed, num_tasks, depth, max_steps));
+    }
+
+    /// The priority a task spawned right now would get under the active
+    /// PCT schedule, or `0` if PCT isn't enabled.
+    fn pct_spawn_priority(&self) -> i32 {
+        let inner = self.inner.exclusive_access();
+        inner.pct.as_ref().map_or(0, PctScheduler::spawn_priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seccomp_deny_blocks_the_filtered_syscall_on_dispatch() {
+        let manager = TaskManager::new_for_test();
+        assert_eq!(manager.push_task_for_test(), 0);
+
+        const SYS_MMAP: usize = 222;
+        manager.install_seccomp_filter(&[(SYS_MMAP, SeccompAction::ErrnoDeny)]);
+
+        assert_eq!(manager.sys_call_inc(SYS_MMAP), Err(-1));
+    }
+
+    /// Two managers seeded identically must pick the exact same sequence
+    /// of `Ready` tasks, including the step where a change point demotes
+    /// whichever task is running.
+    #[test]
+    fn pct_scheduling_is_deterministic_given_the_same_seed() {
+        fn run_schedule(seed: u64) -> Vec<usize> {
+            let manager = TaskManager::new_for_test();
+            for _ in 0..3 {
+                manager.push_task_for_test();
+            }
+            manager.enable_pct(seed, 3, 16);
+
+            let mut order = Vec::new();
+            let mut inner = manager.inner.exclusive_access();
+            // Run the whole 16-step budget so every change point is reached.
+            for _ in 0..16 {
+                let next = inner.select_next_ready().expect("always a Ready task");
+                order.push(next);
+                inner.current_task = next;
+            }
+            order
+        }
+
+        assert_eq!(run_schedule(42), run_schedule(42));
+    }
+
+    #[test]
+    fn pct_spawn_priority_is_above_the_band() {
+        let manager = TaskManager::new_for_test();
+        for _ in 0..3 {
+            manager.push_task_for_test();
+        }
+        manager.enable_pct(7, 3, 16);
+
+        let spawn_priority = manager.pct_spawn_priority();
+        let inner = manager.inner.exclusive_access();
+        let pct = inner.pct.as_ref().unwrap();
+        for task_id in 0..3 {
+            assert!(spawn_priority > pct.priority_of(task_id));
+        }
+    }
+}
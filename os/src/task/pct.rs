@@ -0,0 +1,118 @@
+//! Deterministic Probabilistic Concurrency Testing (PCT) scheduler
+//!
+//! Only used under `#[cfg(test)]`, to make interleavings reproducible
+//! across runs: every task starts with a priority in a fixed band, a handful
+//! of "change points" are drawn up front from a seeded PRNG, and at each one
+//! the task that's currently running gets permanently demoted below the
+//! band. The scheduler always runs the highest-priority `Ready` task.
+
+use alloc::vec::Vec;
+
+/// A small, fast, deterministic PRNG. Not cryptographically secure --
+/// good enough to pick change points reproducibly from a seed.
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    /// Seed the generator; `0` is remapped to `1` since xorshift can't
+    /// escape an all-zero state.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 1 } else { seed },
+        }
+    }
+
+    /// The next pseudo-random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// A pseudo-random value in `0..bound`.
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+/// A PCT schedule over `num_tasks` tasks: each starts with a distinct
+/// priority in `{depth, depth + 1, ..., depth + num_tasks - 1}`, and
+/// `depth - 1` change points are drawn up front from `1..=max_steps`. At
+/// change point `i` (1-indexed), whichever task is running when that step
+/// is reached is demoted to priority `depth - i`.
+pub struct PctScheduler {
+    priorities: Vec<i32>,
+    change_points: Vec<usize>,
+    steps: usize,
+    next_change_point: usize,
+    depth: i32,
+}
+
+impl PctScheduler {
+    /// Build a schedule for `num_tasks` tasks, seeded by `seed`, with
+    /// `depth - 1` change points drawn from `1..=max_steps`.
+    pub fn new(seed: u64, num_tasks: usize, depth: i32, max_steps: usize) -> Self {
+        let mut rng = Xorshift64::new(seed);
+        let priorities = (0..num_tasks).map(|i| depth + i as i32).collect();
+
+        let num_change_points = (depth - 1).max(0) as usize;
+        let mut change_points = Vec::with_capacity(num_change_points);
+        while change_points.len() < num_change_points && max_steps > 0 {
+            let point = 1 + rng.next_below(max_steps as u64) as usize;
+            if !change_points.contains(&point) {
+                change_points.push(point);
+            }
+        }
+        change_points.sort_unstable();
+
+        Self {
+            priorities,
+            change_points,
+            steps: 0,
+            next_change_point: 0,
+            depth,
+        }
+    }
+
+    /// The current priority of `task_id`, or `i32::MIN` if it was never
+    /// assigned one (shouldn't happen for a valid task id).
+    pub fn priority_of(&self, task_id: usize) -> i32 {
+        self.priorities.get(task_id).copied().unwrap_or(i32::MIN)
+    }
+
+    /// The priority a newly spawned task should get: one above the top of
+    /// the band, so it outranks every task the schedule was built with.
+    pub fn spawn_priority(&self) -> i32 {
+        self.depth + self.priorities.len() as i32
+    }
+
+    /// Force `task_id`'s priority to `priority`. Exposed separately from
+    /// `on_step` so callers can replay or inspect demotions.
+    pub fn demote(&mut self, task_id: usize, priority: i32) {
+        if let Some(slot) = self.priorities.get_mut(task_id) {
+            *slot = priority;
+        }
+    }
+
+    /// Record that `running_task` just completed one scheduling step. If
+    /// this step lands on the next change point, demote `running_task`.
+    pub fn on_step(&mut self, running_task: usize) {
+        self.steps += 1;
+        if self.next_change_point < self.change_points.len()
+            && self.steps == self.change_points[self.next_change_point]
+        {
+            self.next_change_point += 1;
+            let new_priority = self.depth - self.next_change_point as i32;
+            self.demote(running_task, new_priority);
+        }
+    }
+
+    /// The highest-priority task among `ready`, or `None` if it's empty.
+    pub fn select_next(&self, ready: impl Iterator<Item = usize>) -> Option<usize> {
+        ready.max_by_key(|&id| self.priority_of(id))
+    }
+}
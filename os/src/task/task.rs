@@ -20,9 +20,12 @@ impl TaskControlBlock {
             return Err("TaskControlBlock::turn_to_running: not a Ready task");
         }
 
+        let now = get_time_ms();
         if self.info.start_time == 0 {
-            self.info.start_time = get_time_ms();
+            self.info.start_time = now;
         }
+        self.info.last_scheduled_at = now;
+        self.info.context_switches += 1;
         self.status = TaskStatus::Running;
         Ok(())
     }
@@ -33,13 +36,29 @@ impl TaskControlBlock {
             return Err("TaskControlBlock::turn_to_ready: not a Running task");
         }
 
+        self.accumulate_cpu_time();
         self.status = TaskStatus::Ready;
         Ok(())
     }
 
     /// Turn the task into `TaskControlBlock::Exited`
     pub fn turn_to_exited(&mut self) {
+        self.accumulate_cpu_time();
         self.status = TaskStatus::Exited;
+        self.info.exit_reason = Some(ExitReason::Normal);
+    }
+
+    /// Turn the task into `TaskControlBlock::Exited`, recording that a
+    /// seccomp `Trap` action killed it instead of a normal `sys_exit`.
+    pub fn turn_to_exited_seccomp_trap(&mut self) {
+        self.accumulate_cpu_time();
+        self.status = TaskStatus::Exited;
+        self.info.exit_reason = Some(ExitReason::SeccompTrap);
+    }
+
+    /// Fold the just-finished `Running` segment into `self.info.cpu_time`.
+    fn accumulate_cpu_time(&mut self) {
+        self.info.cpu_time += get_time_ms().saturating_sub(self.info.last_scheduled_at);
     }
 
     /// Get the task context
@@ -66,6 +85,90 @@ impl TaskControlBlock {
     pub fn sys_call_inc(&mut self, syscall_id: usize) {
         self.info.sys_call_inc(syscall_id);
     }
+
+    /// Get the scheduling policy
+    pub fn sched_policy(&self) -> SchedulerPolicy {
+        self.info.sched_policy
+    }
+
+    /// Replace the scheduling policy
+    pub fn set_sched_policy(&mut self, policy: SchedulerPolicy) {
+        self.info.sched_policy = policy;
+    }
+
+    /// Install (extend) this task's seccomp filter.
+    ///
+    /// `rules` must already have every `syscall_id < MAX_SYSCALL_NUM`: the
+    /// caller (the `sys_seccomp_install` syscall) is responsible for that
+    /// check, since this indexes the filter table directly.
+    pub fn install_seccomp_filter(&mut self, rules: &[(usize, SeccompAction)]) {
+        self.info.seccomp_filter.install(rules);
+    }
+
+    /// The seccomp verdict for `syscall_id`, `Allow` if never overridden.
+    pub fn seccomp_action_for(&self, syscall_id: usize) -> SeccompAction {
+        self.info.seccomp_filter.action_for(syscall_id)
+    }
+
+    /// Why this task exited, or `None` if it hasn't exited yet.
+    pub fn exit_reason(&self) -> Option<ExitReason> {
+        self.info.exit_reason
+    }
+
+    /// Check if the task is stopped for tracing
+    pub fn is_stopped(&self) -> bool {
+        self.status == TaskStatus::Stopped
+    }
+
+    /// Turn a `Running` traced task into `TaskControlBlock::Stopped`
+    pub fn try_turn_to_stopped(&mut self) -> Result<(), &'static str> {
+        if self.status != TaskStatus::Running {
+            return Err("TaskControlBlock::try_turn_to_stopped: not a Running task");
+        }
+        self.accumulate_cpu_time();
+        self.status = TaskStatus::Stopped;
+        Ok(())
+    }
+
+    /// Resume a `Stopped` task back to `Ready`, e.g. on `PTRACE_CONT`
+    pub fn try_turn_to_ready_from_stopped(&mut self) -> Result<(), &'static str> {
+        if self.status != TaskStatus::Stopped {
+            return Err("TaskControlBlock::try_turn_to_ready_from_stopped: not a Stopped task");
+        }
+        self.status = TaskStatus::Ready;
+        Ok(())
+    }
+
+    /// Make `tracer` this task's tracer (`PTRACE_TRACEME`/`PTRACE_ATTACH`)
+    pub fn attach_tracer(&mut self, tracer: usize) {
+        self.info.traced_by = Some(tracer);
+    }
+
+    /// This task's tracer, if any
+    pub fn tracer(&self) -> Option<usize> {
+        self.info.traced_by
+    }
+
+    /// Total milliseconds this task has spent `Running`, including the
+    /// in-progress segment if it's `Running` right now.
+    pub fn cpu_time_ms(&self) -> usize {
+        let in_progress = if self.status == TaskStatus::Running {
+            get_time_ms().saturating_sub(self.info.last_scheduled_at)
+        } else {
+            0
+        };
+        self.info.cpu_time + in_progress
+    }
+
+    /// Milliseconds of wall-clock time since this task was first
+    /// scheduled, or `0` if it never has been.
+    pub fn elapsed_ms(&self) -> usize {
+        if self.info.start_time == 0 {
+            0
+        } else {
+            get_time_ms().saturating_sub(self.info.start_time)
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -76,6 +179,21 @@ pub struct TaskInfo {
     pub start_time: usize,
     /// The number of syscalls called by the task
     pub syscall_times: [u32; MAX_SYSCALL_NUM],
+    /// The scheduling class and priority this task was installed with
+    pub sched_policy: SchedulerPolicy,
+    /// This task's one-way-ratchet seccomp filter
+    pub seccomp_filter: SeccompFilter,
+    /// Why the task exited, or `None` if it's still alive
+    pub exit_reason: Option<ExitReason>,
+    /// The task id tracing this task, if any
+    pub traced_by: Option<usize>,
+    /// Milliseconds spent `Running` in completed segments (the in-progress
+    /// segment, if any, is added on top by `cpu_time_ms`)
+    pub cpu_time: usize,
+    /// When this task was last turned `Running`, `0` if never
+    pub last_scheduled_at: usize,
+    /// How many times this task has been turned `Running`
+    pub context_switches: usize,
 }
 
 impl TaskInfo {
@@ -84,6 +202,13 @@ impl TaskInfo {
             task_cx,
             start_time,
             syscall_times: [0; MAX_SYSCALL_NUM],
+            sched_policy: SchedulerPolicy::default(),
+            seccomp_filter: SeccompFilter::default(),
+            exit_reason: None,
+            traced_by: None,
+            cpu_time: 0,
+            last_scheduled_at: 0,
+            context_switches: 0,
         }
     }
 
@@ -93,6 +218,84 @@ impl TaskInfo {
     }
 }
 
+/// The scheduling class a task runs under, modeled on the `SCHED_*`
+/// policies handled by the Starnix task syscalls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SchedulerClass {
+    /// Fair-share scheduling with a nice value; never preempts `Fifo`/`RoundRobin`.
+    Normal,
+    /// Fixed-priority scheduling. Ranked exactly like `RoundRobin`: a `Fifo`
+    /// task that gives up the CPU also goes behind its equal-priority peers.
+    Fifo,
+    /// Fixed-priority scheduling, round-robin among equal-priority peers.
+    RoundRobin,
+}
+
+/// The lowest valid priority value for `class` (a nice value for `Normal`,
+/// a static priority for `Fifo`/`RoundRobin`).
+pub fn min_priority_for_sched_policy(class: SchedulerClass) -> i32 {
+    match class {
+        SchedulerClass::Normal => MIN_NICE,
+        SchedulerClass::Fifo | SchedulerClass::RoundRobin => MIN_RT_PRIORITY,
+    }
+}
+
+/// The highest valid priority value for `class`.
+pub fn max_priority_for_sched_policy(class: SchedulerClass) -> i32 {
+    match class {
+        SchedulerClass::Normal => MAX_NICE,
+        SchedulerClass::Fifo | SchedulerClass::RoundRobin => MAX_RT_PRIORITY,
+    }
+}
+
+/// Lowest nice value a `Normal` task may request.
+pub const MIN_NICE: i32 = -20;
+/// Highest nice value a `Normal` task may request.
+pub const MAX_NICE: i32 = 19;
+/// Lowest static priority a `Fifo`/`RoundRobin` task may request.
+pub const MIN_RT_PRIORITY: i32 = 1;
+/// Highest static priority a `Fifo`/`RoundRobin` task may request.
+pub const MAX_RT_PRIORITY: i32 = 99;
+
+/// A task's scheduling class together with its priority within that class.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SchedulerPolicy {
+    /// The scheduling class
+    pub class: SchedulerClass,
+    /// A nice value for `Normal`, a static priority for `Fifo`/`RoundRobin`
+    pub priority: i32,
+}
+
+impl Default for SchedulerPolicy {
+    fn default() -> Self {
+        Self {
+            class: SchedulerClass::Normal,
+            priority: 0,
+        }
+    }
+}
+
+impl SchedulerPolicy {
+    /// Build a policy, rejecting priorities outside the range `class` allows.
+    pub fn new(class: SchedulerClass, priority: i32) -> Result<Self, &'static str> {
+        if priority < min_priority_for_sched_policy(class) || priority > max_priority_for_sched_policy(class) {
+            return Err("SchedulerPolicy::new: priority out of range for class");
+        }
+        Ok(Self { class, priority })
+    }
+
+    /// A `(preempts_normal, priority)` key such that, ordered descending,
+    /// it places every `Fifo`/`RoundRobin` task ahead of every `Normal` task
+    /// and otherwise ranks by priority (higher nice is lower priority, so it
+    /// is negated to sort the same direction as the real-time priorities).
+    pub fn scheduling_key(&self) -> (bool, i32) {
+        match self.class {
+            SchedulerClass::Normal => (false, -self.priority),
+            SchedulerClass::Fifo | SchedulerClass::RoundRobin => (true, self.priority),
+        }
+    }
+}
+
 /// The status of a task
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum TaskStatus {
@@ -102,6 +305,86 @@ pub enum TaskStatus {
     Ready,
     /// The task is running
     Running,
+    /// The task is stopped, waiting on its tracer (`PTRACE_CONT`/`PTRACE_SINGLESTEP`)
+    Stopped,
     /// The task has exited
     Exited,
 }
+
+/// Why a task reached `TaskStatus::Exited`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExitReason {
+    /// The task called `sys_exit` (or fell off the end of `main`).
+    Normal,
+    /// A seccomp filter's `Trap` action killed the task before the denied
+    /// syscall could run.
+    SeccompTrap,
+}
+
+/// The verdict a seccomp filter returns for one syscall. Variants are
+/// declared from loosest to strictest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeccompAction {
+    /// Let the syscall run normally.
+    Allow,
+    /// Let the syscall run; distinct from `Allow` only in intent (there is
+    /// no audit log to write the notice to in this kernel).
+    Log,
+    /// Fail the syscall with an error, without running its handler.
+    ErrnoDeny,
+    /// Kill the task instead of running the handler.
+    Trap,
+}
+
+/// A one-way ratchet from syscall id to [`SeccompAction`], installed by
+/// `sys_seccomp_install` and consulted by the syscall dispatch path before
+/// a gated syscall's handler runs. There is no way to loosen a rule once
+/// installed, mirroring Linux's `SECCOMP_SET_MODE_FILTER`.
+#[derive(Copy, Clone, Debug)]
+pub struct SeccompFilter {
+    actions: [SeccompAction; MAX_SYSCALL_NUM],
+}
+
+impl Default for SeccompFilter {
+    fn default() -> Self {
+        Self {
+            actions: [SeccompAction::Allow; MAX_SYSCALL_NUM],
+        }
+    }
+}
+
+impl SeccompFilter {
+    /// Set the verdict for each `(syscall_id, action)` pair, unless it is
+    /// looser than the verdict already installed for that id, in which case
+    /// the rule is ignored.
+    ///
+    /// Every `syscall_id` must already be `< MAX_SYSCALL_NUM`; this indexes
+    /// the table directly and trusts the caller to have checked that.
+    pub fn install(&mut self, rules: &[(usize, SeccompAction)]) {
+        for &(syscall_id, action) in rules {
+            if action as u8 >= self.actions[syscall_id] as u8 {
+                self.actions[syscall_id] = action;
+            }
+        }
+    }
+
+    /// The verdict for `syscall_id`, `Allow` if never overridden.
+    pub fn action_for(&self, syscall_id: usize) -> SeccompAction {
+        self.actions[syscall_id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seccomp_deny_blocks_the_filtered_syscall() {
+        let mut filter = SeccompFilter::default();
+        const SYS_MMAP: usize = 222;
+        assert_eq!(filter.action_for(SYS_MMAP), SeccompAction::Allow);
+
+        filter.install(&[(SYS_MMAP, SeccompAction::ErrnoDeny)]);
+        assert_eq!(filter.action_for(SYS_MMAP), SeccompAction::ErrnoDeny);
+    }
+}